@@ -0,0 +1,337 @@
+//! A process graph assembled at runtime instead of encoded in the type
+//! system, for pipelines whose shape comes from user input or a config file.
+
+use std::any::Any;
+use std::collections::HashMap;
+
+use crate::traversal::Successors;
+use crate::GraphNode;
+
+/// Identifies a node within a [`DynGraph`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NodeId(pub usize);
+
+/// A type-erased value flowing between nodes in a [`DynGraph`].
+pub type DynValue = Box<dyn Any>;
+
+/// A [`GraphNode`] whose input and output have been erased to [`DynValue`].
+pub trait DynGraphNode {
+    /// Runs the node against a type-erased input, returning a type-erased output.
+    fn run(&mut self, input: DynValue) -> DynValue;
+}
+
+/// Adapts a concrete `GraphNode<In, Out>` to [`DynGraphNode`] by downcasting
+/// its input and boxing its output.
+struct Erased<T, In, Out> {
+    node: T,
+    _marker: std::marker::PhantomData<(In, Out)>,
+}
+
+impl<T, In, Out> Erased<T, In, Out> {
+    fn new(node: T) -> Self {
+        Self {
+            node,
+            _marker: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<In: 'static, Out: 'static, T: GraphNode<In, Out>> DynGraphNode for Erased<T, In, Out> {
+    fn run(&mut self, input: DynValue) -> DynValue {
+        let input = *input
+            .downcast::<In>()
+            .unwrap_or_else(|_| panic!("type mismatch feeding a DynGraph node"));
+        Box::new(self.node.run(input))
+    }
+}
+
+/// Clones a type-erased output, monomorphized against the concrete `Out` of
+/// the node that produced it. Captured at [`DynGraph::add_node`] time so fan-out
+/// edges can duplicate a value without the graph itself knowing its type.
+type CloneOutput = fn(&dyn Any) -> DynValue;
+
+fn clone_output<Out: Clone + 'static>(value: &dyn Any) -> DynValue {
+    Box::new(
+        value
+            .downcast_ref::<Out>()
+            .expect("type mismatch cloning a DynGraph output")
+            .clone(),
+    )
+}
+
+struct StoredNode {
+    node: Box<dyn DynGraphNode>,
+    clone_output: CloneOutput,
+    label: Option<String>,
+}
+
+/// Runs `first` then feeds its output to `second`, so two nodes can be
+/// merged into one without discarding either's computation. Used by
+/// [`DynGraph::splice_into`].
+struct Composite {
+    first: Box<dyn DynGraphNode>,
+    second: Box<dyn DynGraphNode>,
+}
+
+impl DynGraphNode for Composite {
+    fn run(&mut self, input: DynValue) -> DynValue {
+        self.second.run(self.first.run(input))
+    }
+}
+
+/// Error produced while running a [`DynGraph`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DynGraphError {
+    /// The edge set contains a cycle, so no topological order exists.
+    Cycle,
+}
+
+impl std::fmt::Display for DynGraphError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DynGraphError::Cycle => write!(f, "the graph contains a cycle"),
+        }
+    }
+}
+
+impl std::error::Error for DynGraphError {}
+
+/// A runtime process graph: type-erased nodes in a map keyed by [`NodeId`],
+/// connected by an explicit edge list. [`DynGraph::run`] executes them in
+/// dependency order via Kahn's algorithm.
+///
+/// A node with more than one incoming edge receives its predecessors'
+/// outputs as a single `Vec<DynValue>`, in the order those edges were added
+/// (see [`DynGraph::run`]) - write such a node's `In` type as
+/// `Vec<DynValue>` and downcast each element.
+#[derive(Default)]
+pub struct DynGraph {
+    nodes: HashMap<NodeId, StoredNode>,
+    edges: Vec<(NodeId, NodeId)>,
+    next_id: usize,
+}
+
+impl DynGraph {
+    /// Creates an empty graph.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a node to the graph and returns its id.
+    ///
+    /// `Out` must be `Clone` so that a node whose output feeds multiple
+    /// successors can duplicate it for each one.
+    pub fn add_node<In, Out>(&mut self, node: impl GraphNode<In, Out> + 'static) -> NodeId
+    where
+        In: 'static,
+        Out: Clone + 'static,
+    {
+        let id = NodeId(self.next_id);
+        self.next_id += 1;
+        self.nodes.insert(
+            id,
+            StoredNode {
+                node: Box::new(Erased::new(node)),
+                clone_output: clone_output::<Out>,
+                label: None,
+            },
+        );
+        id
+    }
+
+    /// Connects the output of `from` to the input of `to`.
+    pub fn add_edge(&mut self, from: NodeId, to: NodeId) {
+        self.edges.push((from, to));
+    }
+
+    /// Attaches a human-readable label (e.g. a node kind) to a node, used
+    /// when rendering the graph with [`DynGraph::to_dot`](crate::dot).
+    pub fn set_label(&mut self, id: NodeId, label: impl Into<String>) {
+        if let Some(node) = self.nodes.get_mut(&id) {
+            node.label = Some(label.into());
+        }
+    }
+
+    /// Returns the label attached to a node via [`DynGraph::set_label`], if any.
+    pub fn label(&self, id: NodeId) -> Option<&str> {
+        self.nodes.get(&id).and_then(|node| node.label.as_deref())
+    }
+
+    /// Replaces the edge list wholesale. Used by [`DynGraph::reduce`](crate::reduce).
+    pub(crate) fn set_edges(&mut self, edges: Vec<(NodeId, NodeId)>) {
+        self.edges = edges;
+    }
+
+    /// Composes `from`'s computation to run before `into`'s, storing the
+    /// result at `into`'s id, and removes `from`. `into` keeps its
+    /// `clone_output` and label. Used by [`DynGraph::reduce`](crate::reduce)
+    /// to collapse a node without losing its work.
+    pub(crate) fn splice_into(&mut self, from: NodeId, into: NodeId) {
+        let first = self
+            .nodes
+            .remove(&from)
+            .expect("splice_into: `from` node must exist")
+            .node;
+        let second = self
+            .nodes
+            .remove(&into)
+            .expect("splice_into: `into` node must exist");
+        self.nodes.insert(
+            into,
+            StoredNode {
+                node: Box::new(Composite {
+                    first,
+                    second: second.node,
+                }),
+                clone_output: second.clone_output,
+                label: second.label,
+            },
+        );
+    }
+
+    /// Returns the ids of every node currently in the graph.
+    pub fn node_ids(&self) -> impl Iterator<Item = NodeId> + '_ {
+        self.nodes.keys().copied()
+    }
+
+    /// Returns the edge list as `(from, to)` pairs.
+    pub fn edges(&self) -> &[(NodeId, NodeId)] {
+        &self.edges
+    }
+
+    /// Runs every node in dependency order, seeding the zero-in-degree nodes
+    /// named in `inputs` and returning the outputs of the sink nodes (those
+    /// with no successors).
+    ///
+    /// The execution order comes from
+    /// [`traversal::topological_sort`](crate::traversal::topological_sort)
+    /// (returning [`DynGraphError::Cycle`] if none exists); each node in that
+    /// order is run and its output fed to its successors.
+    ///
+    /// A node with a single incoming edge is fed its predecessor's output
+    /// as is. A node with `k > 1` incoming edges is fed a `Vec<DynValue>` of
+    /// length `k`, one slot per edge in the order those edges were added via
+    /// [`DynGraph::add_edge`] - so a node recombining branched lanes must
+    /// declare `In = Vec<DynValue>` and downcast each slot itself.
+    pub fn run(
+        &mut self,
+        inputs: HashMap<NodeId, DynValue>,
+    ) -> Result<HashMap<NodeId, DynValue>, DynGraphError> {
+        let order = crate::traversal::topological_sort(self).ok_or(DynGraphError::Cycle)?;
+
+        let successors: HashMap<NodeId, Vec<NodeId>> = self
+            .node_ids()
+            .map(|id| (id, Successors::successors(self, id).collect()))
+            .collect();
+
+        let mut slot_of: HashMap<(NodeId, NodeId), usize> = HashMap::new();
+        let mut slot_count: HashMap<NodeId, usize> = HashMap::new();
+        for &(from, to) in &self.edges {
+            let count = slot_count.entry(to).or_insert(0);
+            slot_of.insert((from, to), *count);
+            *count += 1;
+        }
+
+        let mut pending: HashMap<NodeId, Vec<Option<DynValue>>> = HashMap::new();
+        for (id, value) in inputs {
+            pending.entry(id).or_default().push(Some(value));
+        }
+
+        let mut outputs = HashMap::new();
+
+        for id in order {
+            let mut values: Vec<DynValue> = pending
+                .remove(&id)
+                .unwrap_or_default()
+                .into_iter()
+                .flatten()
+                .collect();
+            let input: DynValue = if values.len() == 1 {
+                values.pop().unwrap()
+            } else {
+                Box::new(values)
+            };
+
+            let stored = self
+                .nodes
+                .get_mut(&id)
+                .expect("node referenced by an edge must exist in the graph");
+            let mut output = Some(stored.node.run(input));
+
+            let succs = successors.get(&id).map(Vec::as_slice).unwrap_or(&[]);
+            for (i, &succ) in succs.iter().enumerate() {
+                let value = if i + 1 == succs.len() {
+                    output.take().unwrap()
+                } else {
+                    (stored.clone_output)(output.as_ref().unwrap().as_ref())
+                };
+
+                let slot = slot_of[&(id, succ)];
+                let slots = pending.entry(succ).or_default();
+                if slots.len() <= slot {
+                    slots.resize_with(slot + 1, || None);
+                }
+                slots[slot] = Some(value);
+            }
+
+            if let Some(output) = output {
+                outputs.insert(id, output);
+            }
+        }
+
+        Ok(outputs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn runs_a_linear_chain_in_order() {
+        let mut graph = DynGraph::new();
+        let a = graph.add_node::<i32, i32>(|x: i32| x + 1);
+        let b = graph.add_node::<i32, i32>(|x: i32| x * 2);
+        graph.add_edge(a, b);
+
+        let mut outputs = graph.run(HashMap::from([(a, Box::new(5) as DynValue)])).unwrap();
+        let out = *outputs.remove(&b).unwrap().downcast::<i32>().unwrap();
+        assert_eq!(out, 12);
+    }
+
+    #[test]
+    fn reports_a_cycle() {
+        let mut graph = DynGraph::new();
+        let a = graph.add_node::<i32, i32>(|x: i32| x);
+        let b = graph.add_node::<i32, i32>(|x: i32| x);
+        graph.add_edge(a, b);
+        graph.add_edge(b, a);
+
+        assert!(matches!(graph.run(HashMap::new()), Err(DynGraphError::Cycle)));
+    }
+
+    #[test]
+    fn branches_and_recombines() {
+        let mut graph = DynGraph::new();
+        let source = graph.add_node::<i32, i32>(|x: i32| x);
+        let double = graph.add_node::<i32, i32>(|x: i32| x * 2);
+        let square = graph.add_node::<i32, i32>(|x: i32| x * x);
+        let join = graph.add_node::<Vec<DynValue>, i32>(|values: Vec<DynValue>| {
+            let mut values = values.into_iter();
+            let a = *values.next().unwrap().downcast::<i32>().unwrap();
+            let b = *values.next().unwrap().downcast::<i32>().unwrap();
+            a + b
+        });
+
+        graph.add_edge(source, double);
+        graph.add_edge(source, square);
+        graph.add_edge(double, join);
+        graph.add_edge(square, join);
+
+        let mut outputs = graph
+            .run(HashMap::from([(source, Box::new(3) as DynValue)]))
+            .unwrap();
+        let out = *outputs.remove(&join).unwrap().downcast::<i32>().unwrap();
+        assert_eq!(out, 3 * 2 + 3 * 3);
+    }
+}
@@ -0,0 +1,190 @@
+//! Loads a [`DynGraph`](crate::dynamic::DynGraph) from a RON document: a
+//! list of named node kinds plus edges, with each kind resolved through a
+//! [`NodeRegistry`] to the constructor that builds it.
+
+use std::collections::HashMap;
+use std::ops::Range;
+
+use serde::Deserialize;
+
+use crate::dynamic::{DynGraph, NodeId};
+
+/// A process graph as written in a RON document: a list of named node kinds
+/// plus directed edges between them.
+#[derive(Debug, Deserialize)]
+pub struct GraphIr {
+    pub nodes: Vec<NodeIr>,
+    pub edges: Vec<EdgeIr>,
+}
+
+/// A single node entry in a [`GraphIr`] document.
+#[derive(Debug, Deserialize)]
+pub struct NodeIr {
+    /// The name this node is referred to by in `edges`.
+    pub name: String,
+    /// The kind string resolved through a [`NodeRegistry`], e.g. `"blur"`.
+    pub kind: String,
+}
+
+/// A directed edge between two named nodes in a [`GraphIr`] document.
+#[derive(Debug, Deserialize)]
+pub struct EdgeIr {
+    pub from: String,
+    pub to: String,
+}
+
+/// Builds and inserts the concrete node for a kind string into a [`DynGraph`].
+type NodeConstructor = Box<dyn Fn(&mut DynGraph) -> NodeId>;
+
+/// Resolves node-kind strings to the constructors that build them.
+#[derive(Default)]
+pub struct NodeRegistry {
+    constructors: HashMap<String, NodeConstructor>,
+}
+
+impl NodeRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the constructor for a node kind, e.g. `"blur"`.
+    pub fn register(
+        &mut self,
+        kind: impl Into<String>,
+        ctor: impl Fn(&mut DynGraph) -> NodeId + 'static,
+    ) {
+        self.constructors.insert(kind.into(), Box::new(ctor));
+    }
+}
+
+/// Error produced while loading a [`GraphIr`] document, pointing at the
+/// byte range in the source text responsible for it.
+#[derive(Debug)]
+pub struct IrError {
+    pub span: Range<usize>,
+    pub kind: IrErrorKind,
+}
+
+/// The specific failure behind an [`IrError`].
+#[derive(Debug)]
+pub enum IrErrorKind {
+    /// The document could not be parsed as RON.
+    Parse(ron::error::SpannedError),
+    /// A node referenced a kind with no matching entry in the [`NodeRegistry`].
+    UnknownKind(String),
+    /// An edge referenced a node name that isn't declared in `nodes`.
+    UnknownNode(String),
+    /// The edge set forms a cycle, so the graph has no valid execution order.
+    Cycle,
+}
+
+impl std::fmt::Display for IrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.kind {
+            IrErrorKind::Parse(err) => write!(f, "failed to parse graph document: {err}"),
+            IrErrorKind::UnknownKind(kind) => write!(f, "unknown node kind `{kind}`"),
+            IrErrorKind::UnknownNode(name) => write!(f, "edge references unknown node `{name}`"),
+            IrErrorKind::Cycle => write!(f, "graph document describes a cycle"),
+        }
+    }
+}
+
+impl std::error::Error for IrError {}
+
+/// A byte offset in `source`, used to point an [`IrError`] at the text
+/// responsible for it. RON's deserializer doesn't retain spans past parsing,
+/// so this falls back to locating `needle`'s first occurrence.
+fn span_of(source: &str, needle: &str) -> Range<usize> {
+    match source.find(needle) {
+        Some(start) => start..start + needle.len(),
+        None => 0..0,
+    }
+}
+
+/// Parses a RON document into a runtime [`DynGraph`], resolving each node's
+/// `kind` through `registry`.
+pub fn from_ron(source: &str, registry: &NodeRegistry) -> Result<DynGraph, IrError> {
+    let ir: GraphIr = ron::de::from_str(source).map_err(|err| IrError {
+        span: 0..0, // RON's `SpannedError` already carries its own line/column.
+        kind: IrErrorKind::Parse(err),
+    })?;
+
+    let mut graph = DynGraph::new();
+    let mut ids = HashMap::new();
+
+    for node in &ir.nodes {
+        let ctor = registry.constructors.get(&node.kind).ok_or_else(|| IrError {
+            span: span_of(source, &node.kind),
+            kind: IrErrorKind::UnknownKind(node.kind.clone()),
+        })?;
+        let id = ctor(&mut graph);
+        graph.set_label(id, node.kind.clone());
+        ids.insert(node.name.clone(), id);
+    }
+
+    for edge in &ir.edges {
+        let from = *ids.get(&edge.from).ok_or_else(|| IrError {
+            span: span_of(source, &edge.from),
+            kind: IrErrorKind::UnknownNode(edge.from.clone()),
+        })?;
+        let to = *ids.get(&edge.to).ok_or_else(|| IrError {
+            span: span_of(source, &edge.to),
+            kind: IrErrorKind::UnknownNode(edge.to.clone()),
+        })?;
+        graph.add_edge(from, to);
+    }
+
+    if crate::traversal::has_cycle(&graph) {
+        return Err(IrError {
+            span: 0..source.len(),
+            kind: IrErrorKind::Cycle,
+        });
+    }
+
+    Ok(graph)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn registry() -> NodeRegistry {
+        let mut registry = NodeRegistry::new();
+        registry.register("identity", |graph| graph.add_node::<i32, i32>(|x: i32| x));
+        registry
+    }
+
+    #[test]
+    fn rejects_an_unknown_kind() {
+        let source = r#"(
+            nodes: [(name: "a", kind: "blur")],
+            edges: [],
+        )"#;
+
+        let err = from_ron(source, &registry()).err().expect("expected an error");
+        assert!(matches!(err.kind, IrErrorKind::UnknownKind(kind) if kind == "blur"));
+    }
+
+    #[test]
+    fn rejects_an_unknown_node_in_an_edge() {
+        let source = r#"(
+            nodes: [(name: "a", kind: "identity")],
+            edges: [(from: "a", to: "b")],
+        )"#;
+
+        let err = from_ron(source, &registry()).err().expect("expected an error");
+        assert!(matches!(err.kind, IrErrorKind::UnknownNode(name) if name == "b"));
+    }
+
+    #[test]
+    fn rejects_a_cycle() {
+        let source = r#"(
+            nodes: [(name: "a", kind: "identity"), (name: "b", kind: "identity")],
+            edges: [(from: "a", to: "b"), (from: "b", to: "a")],
+        )"#;
+
+        let err = from_ron(source, &registry()).err().expect("expected an error");
+        assert!(matches!(err.kind, IrErrorKind::Cycle));
+    }
+}
@@ -0,0 +1,57 @@
+//! A node that folds a fanned-out tuple back down to a single value.
+
+use crate::GraphNode;
+
+/// A node that recombines a fanned-out tuple `(A, B, ...)` into one output,
+/// built from a closure taking one argument per lane.
+///
+/// Paired with the `graph!` macro's `=> join(|a, b| ...)` syntax.
+pub struct Join<F> {
+    f: F,
+}
+
+impl<F> Join<F> {
+    /// Wraps `f` as a merge node.
+    pub fn new(f: F) -> Self {
+        Self { f }
+    }
+}
+
+macro_rules! impl_graph_node_for_join {
+    ($($In:ident),+) => {
+        impl<Out, Func, $($In),+> GraphNode<($($In,)+), Out> for Join<Func>
+        where
+            Func: FnMut($($In),+) -> Out,
+        {
+            #[allow(non_snake_case)]
+            fn run(&mut self, input: ($($In,)+)) -> Out {
+                let ($($In,)+) = input;
+                (self.f)($($In),+)
+            }
+        }
+    };
+}
+
+impl_graph_node_for_join!(A, B);
+impl_graph_node_for_join!(A, B, C);
+impl_graph_node_for_join!(A, B, C, D);
+impl_graph_node_for_join!(A, B, C, D, E);
+impl_graph_node_for_join!(A, B, C, D, E, F);
+impl_graph_node_for_join!(A, B, C, D, E, F, G);
+impl_graph_node_for_join!(A, B, C, D, E, F, G, H);
+
+#[cfg(test)]
+mod tests {
+    use crate::{graph, GraphNode};
+
+    #[test]
+    fn joins_branched_lanes_back_together() {
+        let mut pipeline = graph! {
+            => |x: i32| (x, x)
+            => (|a: i32| a + 1, |b: i32| b * 2)
+            => join(|a: i32, b: i32| a + b)
+        };
+
+        assert_eq!(pipeline.run(3), (3 + 1) + (3 * 2));
+    }
+}
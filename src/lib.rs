@@ -1,5 +1,14 @@
 #![doc = include_str!("../README.md")]
 
+pub mod dot;
+pub mod dynamic;
+pub mod ir;
+mod join;
+pub mod reduce;
+pub mod traversal;
+
+pub use join::Join;
+
 /// The core trait for any node in a process graph.
 /// Each node takes an input `In` and produces an output `Out`.
 pub trait GraphNode<In, Out> {
@@ -111,6 +120,8 @@ impl_graph_node_for_tuples!(
 /// - `=> expr`: Chains the previous node's output to the next single node's input using `pipe`.
 /// - `=> (expr1 expr2 ...)`: Branches the previous node's output to multiple nodes
 ///   (provided as a tuple), collecting their outputs into a tuple.
+/// - `=> join(|a, b, ...| ...)`: Recombines a fanned-out tuple back into a single
+///   value, using a [`Join`] node whose closure takes one argument per lane.
 #[macro_export]
 macro_rules! graph {
     (=> $first_node:expr $(=> $($rest:tt)*)?) => {
@@ -121,6 +132,13 @@ macro_rules! graph {
         $current_pipeline
     };
 
+    (@build $current_pipeline:expr => join($join_fn:expr) $(=> $($rest:tt)*)?) => {
+        {
+            let next_pipeline = $current_pipeline.pipe($crate::Join::new($join_fn));
+            graph!(@build next_pipeline $(=> $($rest)*)?)
+        }
+    };
+
     (@build $current_pipeline:expr => $next_node_expr:expr $(=> $($rest:tt)*)?) => {
         {
             let next_pipeline = $current_pipeline.pipe($next_node_expr);
@@ -0,0 +1,179 @@
+//! A DAG reduction pass for [`DynGraph`] that collapses single-successor
+//! intermediate nodes into their dependent, so fewer nodes remain without
+//! changing what running the graph produces.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::dynamic::{DynGraph, NodeId};
+use crate::traversal::Successors;
+
+/// Tracks which surviving node a collapsed node's edges have been redirected
+/// to.
+struct UnionFind {
+    parent: HashMap<NodeId, NodeId>,
+}
+
+impl UnionFind {
+    fn new(ids: impl Iterator<Item = NodeId>) -> Self {
+        Self {
+            parent: ids.map(|id| (id, id)).collect(),
+        }
+    }
+
+    fn find(&mut self, id: NodeId) -> NodeId {
+        let parent = self.parent[&id];
+        if parent == id {
+            id
+        } else {
+            let root = self.find(parent);
+            self.parent.insert(id, root);
+            root
+        }
+    }
+
+    /// Redirects `from`'s representative to `into`'s representative.
+    fn union(&mut self, from: NodeId, into: NodeId) {
+        let from_root = self.find(from);
+        let into_root = self.find(into);
+        self.parent.insert(from_root, into_root);
+    }
+}
+
+impl DynGraph {
+    /// Collapses redundant intermediate nodes, preserving every output node
+    /// and the result of running the graph.
+    ///
+    /// Every node is classified as an input (no incoming edges), an output
+    /// (no outgoing edges), or intermediate (both). An intermediate node
+    /// with a single dependent, where that dependent has no other
+    /// predecessor, is collapsed: its computation is composed into its
+    /// dependent via [`DynGraph::splice_into`], and its predecessors are
+    /// spliced directly to the dependent, transitively, via a union-find
+    /// tracking each collapsed node's surviving representative. Nodes are
+    /// visited in reverse topological order so chains of collapses compose
+    /// in the right sequence. An intermediate node is kept when it has more
+    /// than one dependent (a genuine branch point) or when its dependent
+    /// also has other predecessors (a genuine join point).
+    pub fn reduce(&mut self) {
+        let mut in_degree: HashMap<NodeId, usize> = self.node_ids().map(|id| (id, 0)).collect();
+        let mut out_degree: HashMap<NodeId, usize> = HashMap::new();
+        let mut successor: HashMap<NodeId, NodeId> = HashMap::new();
+        for id in self.node_ids() {
+            let succs: Vec<NodeId> = Successors::successors(self, id).collect();
+            out_degree.insert(id, succs.len());
+            if let Some(&only) = succs.first() {
+                successor.insert(id, only);
+            }
+            for succ in succs {
+                *in_degree.get_mut(&succ).unwrap() += 1;
+            }
+        }
+
+        let order =
+            crate::traversal::topological_sort(self).unwrap_or_else(|| self.node_ids().collect());
+
+        let mut union_find = UnionFind::new(self.node_ids());
+
+        for &id in order.iter().rev() {
+            let is_input = in_degree[&id] == 0;
+            let is_output = out_degree[&id] == 0;
+            let is_intermediate = !is_input && !is_output;
+
+            if is_intermediate && out_degree[&id] == 1 {
+                let succ = successor[&id];
+                if in_degree[&succ] == 1 {
+                    let host = union_find.find(succ);
+                    self.splice_into(id, host);
+                    union_find.union(id, host);
+                }
+            }
+        }
+
+        let mut seen = HashSet::new();
+        let mut new_edges = Vec::new();
+        for &(from, to) in self.edges() {
+            let from = union_find.find(from);
+            let to = union_find.find(to);
+            if from != to && seen.insert((from, to)) {
+                new_edges.push((from, to));
+            }
+        }
+        self.set_edges(new_edges);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::dynamic::DynValue;
+
+    use super::*;
+
+    #[test]
+    fn reduce_preserves_run_output() {
+        let mut graph = DynGraph::new();
+        let n0 = graph.add_node::<i32, i32>(|x: i32| x);
+        let n1 = graph.add_node::<i32, i32>(|x: i32| x * 2);
+        let n2 = graph.add_node::<i32, i32>(|x: i32| x + 1);
+        graph.add_edge(n0, n1);
+        graph.add_edge(n1, n2);
+
+        let before = *graph
+            .run(HashMap::from([(n0, Box::new(5) as DynValue)]))
+            .unwrap()
+            .remove(&n2)
+            .unwrap()
+            .downcast::<i32>()
+            .unwrap();
+        assert_eq!(before, 11);
+
+        graph.reduce();
+
+        let after = *graph
+            .run(HashMap::from([(n0, Box::new(5) as DynValue)]))
+            .unwrap()
+            .remove(&n2)
+            .unwrap()
+            .downcast::<i32>()
+            .unwrap();
+        assert_eq!(after, before);
+    }
+
+    #[test]
+    fn reduce_preserves_edge_order_for_an_uncollapsed_join() {
+        let mut graph = DynGraph::new();
+        let source = graph.add_node::<i32, i32>(|x: i32| x);
+        let left = graph.add_node::<i32, i32>(|x: i32| x + 1);
+        let right = graph.add_node::<i32, i32>(|x: i32| x * 2);
+        let join = graph.add_node::<Vec<DynValue>, i32>(|values: Vec<DynValue>| {
+            let mut values = values.into_iter();
+            let a = *values.next().unwrap().downcast::<i32>().unwrap();
+            let b = *values.next().unwrap().downcast::<i32>().unwrap();
+            a - b
+        });
+
+        graph.add_edge(source, left);
+        graph.add_edge(source, right);
+        graph.add_edge(left, join);
+        graph.add_edge(right, join);
+
+        fn run_join(graph: &mut DynGraph, source: NodeId, join: NodeId) -> i32 {
+            *graph
+                .run(HashMap::from([(source, Box::new(10) as DynValue)]))
+                .unwrap()
+                .remove(&join)
+                .unwrap()
+                .downcast::<i32>()
+                .unwrap()
+        }
+
+        let before = run_join(&mut graph, source, join);
+        assert_eq!(before, (10 + 1) - (10 * 2));
+
+        graph.reduce();
+
+        let after = run_join(&mut graph, source, join);
+        assert_eq!(after, before);
+    }
+}
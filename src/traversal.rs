@@ -0,0 +1,154 @@
+//! Graph-traversal traits so cycle detection, topological sort, and
+//! reachability are written once against [`Successors`] instead of directly
+//! against [`DynGraph`](crate::dynamic::DynGraph).
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+use crate::dynamic::{DynGraph, NodeId};
+
+/// A directed graph with a fixed, enumerable set of nodes.
+pub trait DirectedGraph {
+    /// The type used to identify a node.
+    type Node: Copy + Eq + Hash;
+
+    /// The number of nodes in the graph.
+    fn num_nodes(&self) -> usize;
+
+    /// Every node in the graph.
+    fn nodes(&self) -> impl Iterator<Item = Self::Node>;
+}
+
+/// A [`DirectedGraph`] that can report a node's successors.
+pub trait Successors: DirectedGraph {
+    /// The nodes reachable by a single edge out of `node`.
+    fn successors(&self, node: Self::Node) -> impl Iterator<Item = Self::Node> + '_;
+}
+
+impl DirectedGraph for DynGraph {
+    type Node = NodeId;
+
+    fn num_nodes(&self) -> usize {
+        self.node_ids().count()
+    }
+
+    fn nodes(&self) -> impl Iterator<Item = NodeId> {
+        self.node_ids()
+    }
+}
+
+impl Successors for DynGraph {
+    fn successors(&self, node: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        self.edges()
+            .iter()
+            .filter(move |&&(from, _)| from == node)
+            .map(|&(_, to)| to)
+    }
+}
+
+/// Computes the in-degree of every node in `graph`.
+fn in_degrees<G: Successors>(graph: &G) -> HashMap<G::Node, usize> {
+    let mut in_degree: HashMap<G::Node, usize> = graph.nodes().map(|node| (node, 0)).collect();
+    for node in graph.nodes() {
+        for succ in graph.successors(node) {
+            *in_degree.get_mut(&succ).unwrap() += 1;
+        }
+    }
+    in_degree
+}
+
+/// Topologically sorts the nodes of `graph` via Kahn's algorithm: the
+/// in-degree of every node is computed up front, a queue is seeded with the
+/// zero-in-degree nodes, and each popped node is appended to the order and
+/// its successors' in-degree decremented, enqueueing any that reach zero.
+/// Returns `None` if fewer nodes were ordered than exist in the graph, i.e.
+/// the edge set contains a cycle.
+pub fn topological_sort<G: Successors>(graph: &G) -> Option<Vec<G::Node>> {
+    let mut in_degree = in_degrees(graph);
+    let mut queue: VecDeque<G::Node> = in_degree
+        .iter()
+        .filter(|&(_, &degree)| degree == 0)
+        .map(|(&node, _)| node)
+        .collect();
+
+    let mut order = Vec::new();
+    while let Some(node) = queue.pop_front() {
+        order.push(node);
+        for succ in graph.successors(node) {
+            let degree = in_degree.get_mut(&succ).unwrap();
+            *degree -= 1;
+            if *degree == 0 {
+                queue.push_back(succ);
+            }
+        }
+    }
+
+    if order.len() == graph.num_nodes() {
+        Some(order)
+    } else {
+        None
+    }
+}
+
+/// Returns `true` if `graph`'s edges contain a cycle.
+pub fn has_cycle<G: Successors>(graph: &G) -> bool {
+    topological_sort(graph).is_none()
+}
+
+/// Returns every node reachable from `start`, including `start` itself.
+pub fn reachable<G: Successors>(graph: &G, start: G::Node) -> HashSet<G::Node> {
+    let mut visited = HashSet::new();
+    let mut stack = vec![start];
+    while let Some(node) = stack.pop() {
+        if visited.insert(node) {
+            stack.extend(graph.successors(node));
+        }
+    }
+    visited
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dynamic::DynGraph;
+
+    #[test]
+    fn orders_nodes_before_their_successors() {
+        let mut graph = DynGraph::new();
+        let a = graph.add_node::<i32, i32>(|x: i32| x);
+        let b = graph.add_node::<i32, i32>(|x: i32| x);
+        let c = graph.add_node::<i32, i32>(|x: i32| x);
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+
+        let order = topological_sort(&graph).unwrap();
+        assert_eq!(order.len(), 3);
+        assert!(order.iter().position(|&n| n == a) < order.iter().position(|&n| n == b));
+        assert!(order.iter().position(|&n| n == b) < order.iter().position(|&n| n == c));
+    }
+
+    #[test]
+    fn detects_a_cycle() {
+        let mut graph = DynGraph::new();
+        let a = graph.add_node::<i32, i32>(|x: i32| x);
+        let b = graph.add_node::<i32, i32>(|x: i32| x);
+        graph.add_edge(a, b);
+        graph.add_edge(b, a);
+
+        assert!(has_cycle(&graph));
+    }
+
+    #[test]
+    fn reachable_includes_start_and_its_descendants() {
+        let mut graph = DynGraph::new();
+        let a = graph.add_node::<i32, i32>(|x: i32| x);
+        let b = graph.add_node::<i32, i32>(|x: i32| x);
+        let c = graph.add_node::<i32, i32>(|x: i32| x);
+        graph.add_edge(a, b);
+
+        let reached = reachable(&graph, a);
+        assert!(reached.contains(&a));
+        assert!(reached.contains(&b));
+        assert!(!reached.contains(&c));
+    }
+}